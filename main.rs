@@ -1,7 +1,13 @@
 use std::f64::consts::PI;
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+use serde::{Deserialize, Serialize};
+
 /// Basic stop (pool)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct Stop {
     id: usize,
     name: String,
@@ -45,36 +51,156 @@ fn meters_to_minutes(meters: f64, avg_kmph: f64) -> f64 {
     (km / avg_kmph) * 60.0
 }
 
+/// Cached pairwise Haversine distances for a route's stops, keyed by stop id
+/// rather than position so it stays valid while NN/2-opt reorder `stops`.
+/// Index 0 of `matrix` is the depot; built once per optimization pass instead
+/// of recomputing the same trig thousands of times in the inner loops.
+#[derive(Clone, Debug)]
+struct DistanceMatrix {
+    index: std::collections::HashMap<usize, usize>, // stop id -> matrix row/col
+    matrix: Vec<Vec<f64>>,
+}
+
+impl DistanceMatrix {
+    fn build(depot: Option<&Stop>, stops: &[Stop]) -> Self {
+        let mut points = Vec::with_capacity(stops.len() + 1);
+        points.push(depot.map(|d| (d.lat, d.lon)).unwrap_or((0.0, 0.0)));
+        let mut index = std::collections::HashMap::with_capacity(stops.len());
+        for (i, s) in stops.iter().enumerate() {
+            index.insert(s.id, i + 1);
+            points.push((s.lat, s.lon));
+        }
+
+        let n = points.len();
+        let mut matrix = vec![vec![0.0; n]; n];
+        for a in 0..n {
+            for b in (a + 1)..n {
+                let d = haversine_meters(points[a].0, points[a].1, points[b].0, points[b].1);
+                matrix[a][b] = d;
+                matrix[b][a] = d;
+            }
+        }
+
+        Self { index, matrix }
+    }
+
+    fn depot_to(&self, stop_id: usize) -> f64 {
+        self.matrix[0][self.index[&stop_id]]
+    }
+
+    fn between(&self, a_id: usize, b_id: usize) -> f64 {
+        self.matrix[self.index[&a_id]][self.index[&b_id]]
+    }
+}
+
+/// Wraps a stop's id and (lat, lon) for the R-tree used by the spatial
+/// nearest-neighbor construction. Haversine is near-monotonic with planar
+/// distance over a small metro area, so Euclidean nearest queries against
+/// this index return the same neighbor the Haversine-based linear scan would
+/// -- but only once `distance_2` accounts for longitude degrees shrinking
+/// relative to latitude degrees away from the equator (see `distance_2`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct StopPoint {
+    id: usize,
+    point: [f64; 2],
+}
+
+impl RTreeObject for StopPoint {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(self.point)
+    }
+}
+
+impl PointDistance for StopPoint {
+    /// Squared planar distance, with the longitude delta scaled by
+    /// `cos(mean_lat)` so a degree of longitude is weighted the same as a
+    /// degree of latitude would be at this latitude. Without this, a raw
+    /// `(lat, lon)` Euclidean distance overweights longitude away from the
+    /// equator (at ~40°N a longitude degree is only ~0.77x a latitude
+    /// degree) and can disagree with the Haversine-based linear scan it's
+    /// meant to stand in for, even within `RTREE_MAX_EXTENT_DEGREES`.
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let mean_lat_rad = ((self.point[0] + point[0]) / 2.0) * PI / 180.0;
+        let dlat = self.point[0] - point[0];
+        let dlon = (self.point[1] - point[1]) * mean_lat_rad.cos();
+        dlat * dlat + dlon * dlon
+    }
+}
+
 /// Route for a single cleaner
 #[derive(Clone, Debug)]
 struct Route {
     id: usize,
-    stops: Vec<Stop>, // order matters
+    stops: Vec<Stop>,    // order matters
     depot: Option<Stop>, // optional start/end point (e.g., garage)
+    // Populated at the start of `optimize`/`optimize_annealing` and invalidated
+    // whenever `RouteManager` adds, removes, or reassigns a stop.
+    dist_matrix: Option<DistanceMatrix>,
 }
 
 impl Route {
+    /// Above this many stops, Held-Karp's `2^n` state space makes `optimize_exact`
+    /// impractical, so it falls back to the heuristic instead.
+    const EXACT_SOLVER_MAX_STOPS: usize = 15;
+
+    /// Above this lat/lon extent (degrees), Haversine and planar distance can
+    /// diverge enough that the R-tree's Euclidean nearest query disagrees with
+    /// the Haversine-based linear scan, so `optimize` falls back to it.
+    const RTREE_MAX_EXTENT_DEGREES: f64 = 1.0;
+
     fn new(id: usize, depot: Option<Stop>) -> Self {
         Self {
             id,
             stops: Vec::new(),
             depot,
+            dist_matrix: None,
         }
     }
 
+    /// (Re)build the cached distance matrix from the route's current stops.
+    fn rebuild_distance_matrix(&mut self) {
+        self.dist_matrix = Some(DistanceMatrix::build(self.depot.as_ref(), &self.stops));
+    }
+
+    /// Drop the cached distance matrix; called by `RouteManager` whenever stops
+    /// are added, removed, or reassigned so a stale matrix can't be read.
+    fn invalidate_distance_matrix(&mut self) {
+        self.dist_matrix = None;
+    }
+
     fn total_distance_meters(&self) -> f64 {
+        self.path_distance(&self.stops)
+    }
+
+    /// Total path distance (depot -> stops in the given order -> depot) using
+    /// this route's depot and cached distance matrix. Takes an arbitrary stop
+    /// ordering rather than always reading `self.stops` so callers like
+    /// `three_opt` can score candidate reconnections without mutating the
+    /// route.
+    fn path_distance(&self, stops: &[Stop]) -> f64 {
         let mut dist = 0.0;
-        let mut prev_opt = self.depot.as_ref();
-        for s in &self.stops {
-            if let Some(prev) = prev_opt {
-                dist += haversine_meters(prev.lat, prev.lon, s.lat, s.lon);
-            }
-            prev_opt = Some(s);
+        // `prev` is `None` only before the first stop; at that point the edge (if
+        // any) comes from the depot, which the loop below handles explicitly.
+        let mut prev: Option<&Stop> = None;
+        for s in stops {
+            dist += match (prev, &self.depot, &self.dist_matrix) {
+                (Some(p), _, Some(matrix)) => matrix.between(p.id, s.id),
+                (Some(p), _, None) => haversine_meters(p.lat, p.lon, s.lat, s.lon),
+                (None, Some(_), Some(matrix)) => matrix.depot_to(s.id),
+                (None, Some(depot), None) => haversine_meters(depot.lat, depot.lon, s.lat, s.lon),
+                (None, None, _) => 0.0,
+            };
+            prev = Some(s);
         }
         // return to depot if depot exists
         if let Some(depot) = &self.depot {
-            if let Some(last) = self.stops.last() {
-                dist += haversine_meters(last.lat, last.lon, depot.lat, depot.lon);
+            if let Some(last) = stops.last() {
+                dist += match &self.dist_matrix {
+                    Some(matrix) => matrix.depot_to(last.id),
+                    None => haversine_meters(last.lat, last.lon, depot.lat, depot.lon),
+                };
             }
         }
         dist
@@ -96,10 +222,10 @@ impl Route {
         self.stops.clear();
 
         // determine starting point
-        let mut current = if let Some(depot) = &self.depot {
-            depot.clone()
+        let (mut current, mut current_is_depot) = if let Some(depot) = &self.depot {
+            (depot.clone(), true)
         } else {
-            remaining.remove(0)
+            (remaining.remove(0), false)
         };
 
         // if depot was not originally in remaining (i.e., we used depot as start), ensure we don't include it
@@ -111,7 +237,11 @@ impl Route {
             let mut best_idx = 0usize;
             let mut best_dist = f64::INFINITY;
             for (i, s) in remaining.iter().enumerate() {
-                let d = haversine_meters(current.lat, current.lon, s.lat, s.lon);
+                let d = match &self.dist_matrix {
+                    Some(matrix) if current_is_depot => matrix.depot_to(s.id),
+                    Some(matrix) => matrix.between(current.id, s.id),
+                    None => haversine_meters(current.lat, current.lon, s.lat, s.lon),
+                };
                 if d < best_dist {
                     best_dist = d;
                     best_idx = i;
@@ -120,6 +250,96 @@ impl Route {
             let next = remaining.remove(best_idx);
             self.stops.push(next.clone());
             current = next;
+            current_is_depot = false;
+        }
+    }
+
+    /// Nearest-neighbor construction starting from an arbitrary stop (by its
+    /// position in the current `self.stops`) instead of the depot. Unlike
+    /// `build_nearest_neighbor`, which always anchors at the depot when one is
+    /// present, this gives `optimize_multistart` a genuinely different initial
+    /// path to run local search from on each restart.
+    fn build_nearest_neighbor_from(&mut self, start_idx: usize) {
+        if self.stops.is_empty() {
+            return;
+        }
+        let mut remaining = self.stops.clone();
+        self.stops.clear();
+
+        let mut current = remaining.remove(start_idx);
+        self.stops.push(current.clone());
+
+        while !remaining.is_empty() {
+            let mut best_idx = 0usize;
+            let mut best_dist = f64::INFINITY;
+            for (i, s) in remaining.iter().enumerate() {
+                let d = match &self.dist_matrix {
+                    Some(matrix) => matrix.between(current.id, s.id),
+                    None => haversine_meters(current.lat, current.lon, s.lat, s.lon),
+                };
+                if d < best_dist {
+                    best_dist = d;
+                    best_idx = i;
+                }
+            }
+            let next = remaining.remove(best_idx);
+            self.stops.push(next.clone());
+            current = next;
+        }
+    }
+
+    /// The larger of the route's lat and lon spans (depot included), in degrees.
+    /// Used to decide whether the R-tree's planar nearest-neighbor query is
+    /// still a safe stand-in for the Haversine-based linear scan.
+    fn coordinate_extent_degrees(&self) -> f64 {
+        let points = self.depot.iter().chain(self.stops.iter());
+        let (mut lat_min, mut lat_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut lon_min, mut lon_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for s in points {
+            lat_min = lat_min.min(s.lat);
+            lat_max = lat_max.max(s.lat);
+            lon_min = lon_min.min(s.lon);
+            lon_max = lon_max.max(s.lon);
+        }
+        (lat_max - lat_min).max(lon_max - lon_min)
+    }
+
+    /// Nearest-neighbor construction backed by an R-tree, for routes with many
+    /// stops where the O(n^2) linear scan in `build_nearest_neighbor` becomes
+    /// the bottleneck: each step queries the tree for the closest remaining
+    /// point instead of scanning every remaining stop.
+    fn build_nearest_neighbor_rtree(&mut self) {
+        if self.stops.is_empty() {
+            return;
+        }
+        let by_id: std::collections::HashMap<usize, Stop> =
+            self.stops.iter().map(|s| (s.id, s.clone())).collect();
+        let mut tree: RTree<StopPoint> = RTree::bulk_load(
+            self.stops
+                .iter()
+                .map(|s| StopPoint { id: s.id, point: [s.lat, s.lon] })
+                .collect(),
+        );
+
+        // determine starting point, mirroring build_nearest_neighbor
+        let (mut current_point, start_stop) = if let Some(depot) = &self.depot {
+            ([depot.lat, depot.lon], None)
+        } else {
+            let first = self.stops[0].clone();
+            ([first.lat, first.lon], Some(first))
+        };
+
+        self.stops.clear();
+        if let Some(first) = start_stop {
+            tree.remove(&StopPoint { id: first.id, point: [first.lat, first.lon] });
+            self.stops.push(first);
+        }
+
+        while let Some(nearest) = tree.nearest_neighbor(current_point).cloned() {
+            tree.remove(&nearest);
+            let stop = by_id[&nearest.id].clone();
+            current_point = [stop.lat, stop.lon];
+            self.stops.push(stop);
         }
     }
 
@@ -148,32 +368,48 @@ impl Route {
         }
     }
 
+    /// Resolves a path index to (lat, lon, stop_id) for distance lookups.
+    /// `stop_id` is `None` for the depot, which the distance matrix keys
+    /// separately from stops. An out-of-range index (the convention used for
+    /// "off either end of the path") resolves to the depot if present,
+    /// otherwise to the last stop, whichever end of the path it is off.
+    fn endpoint(&self, idx_opt: Option<isize>) -> (f64, f64, Option<usize>) {
+        let n = self.stops.len();
+        match idx_opt {
+            Some(idx) if idx >= 0 && (idx as usize) < n => {
+                let s = &self.stops[idx as usize];
+                (s.lat, s.lon, Some(s.id))
+            }
+            _ => {
+                if let Some(depot) = &self.depot {
+                    (depot.lat, depot.lon, None)
+                } else if n == 0 {
+                    (0.0, 0.0, None)
+                } else {
+                    let s = &self.stops[n - 1];
+                    (s.lat, s.lon, Some(s.id))
+                }
+            }
+        }
+    }
+
+    /// Distance between two resolved endpoints, using the cached distance
+    /// matrix when available instead of recomputing Haversine.
+    fn endpoint_distance(&self, p: (f64, f64, Option<usize>), q: (f64, f64, Option<usize>)) -> f64 {
+        match (&self.dist_matrix, p.2, q.2) {
+            (Some(matrix), Some(a), Some(b)) => matrix.between(a, b),
+            (Some(matrix), Some(a), None) | (Some(matrix), None, Some(a)) => matrix.depot_to(a),
+            (Some(_), None, None) => 0.0,
+            (None, _, _) => haversine_meters(p.0, p.1, q.0, q.1),
+        }
+    }
+
     /// compute change in distance if we reverse segment (i+1..=k)
     fn two_opt_swap_delta(&self, i: usize, k: usize) -> f64 {
         // nodes: A - B ... C - D
         // edges removed: AB and CD
         // edges added: AC and BD
         let n = self.stops.len();
-        let get_point = |idx_opt: Option<isize>| -> (f64, f64) {
-            match idx_opt {
-                Some(idx) if idx >= 0 && (idx as usize) < n => {
-                    let s = &self.stops[idx as usize];
-                    (s.lat, s.lon)
-                }
-                _ => {
-                    // depot or out-of-range -> use depot (if present) otherwise repeat endpoint
-                    if let Some(depot) = &self.depot {
-                        (depot.lat, depot.lon)
-                    } else if n == 0 {
-                        (0.0, 0.0)
-                    } else {
-                        let s = &self.stops[n - 1];
-                        (s.lat, s.lon)
-                    }
-                }
-            }
-        };
-
         let a_idx = if i == 0 {
             None
         } else {
@@ -183,13 +419,15 @@ impl Route {
         let c_idx = Some(k as isize);
         let d_idx = if k + 1 >= n { None } else { Some((k + 1) as isize) };
 
-        let (a_lat, a_lon) = get_point(a_idx);
-        let (b_lat, b_lon) = get_point(b_idx);
-        let (c_lat, c_lon) = get_point(c_idx);
-        let (d_lat, d_lon) = get_point(d_idx);
+        let (a, b, c, d) = (
+            self.endpoint(a_idx),
+            self.endpoint(b_idx),
+            self.endpoint(c_idx),
+            self.endpoint(d_idx),
+        );
 
-        let removed = haversine_meters(a_lat, a_lon, b_lat, b_lon) + haversine_meters(c_lat, c_lon, d_lat, d_lon);
-        let added = haversine_meters(a_lat, a_lon, c_lat, c_lon) + haversine_meters(b_lat, b_lon, d_lat, d_lon);
+        let removed = self.endpoint_distance(a, b) + self.endpoint_distance(c, d);
+        let added = self.endpoint_distance(a, c) + self.endpoint_distance(b, d);
         added - removed
     }
 
@@ -198,7 +436,312 @@ impl Route {
         self.stops[i..=k].reverse();
     }
 
-    /// Convenience: optimize by building NN then 2-opt
+    /// Or-opt: for chain lengths 1..=3, tries removing a contiguous run of
+    /// stops and reinserting it (in either orientation) at every other
+    /// position, applying the first reinsertion that reduces total distance.
+    /// Unlike 2-opt, which can only reverse a segment in place, this lets a
+    /// single stop or short chain relocate to a different part of the route,
+    /// which often breaks through local optima reversals alone get stuck on.
+    fn or_opt(&mut self) {
+        let mut improved = true;
+        while improved {
+            improved = false;
+            if self.apply_first_improving_or_opt_move() {
+                improved = true;
+            }
+        }
+    }
+
+    /// Scans all (segment length, start position, reinsertion point,
+    /// orientation) combinations and applies the first one that reduces total
+    /// distance. Returns whether a move was applied.
+    fn apply_first_improving_or_opt_move(&mut self) -> bool {
+        let n = self.stops.len();
+        for seg_len in 1..=3usize {
+            if seg_len >= n {
+                continue;
+            }
+            for start in 0..=(n - seg_len) {
+                let end = start + seg_len - 1; // inclusive
+
+                let before = if start == 0 { None } else { Some((start - 1) as isize) };
+                let after = if end + 1 >= n { None } else { Some((end + 1) as isize) };
+                let prev = self.endpoint(before);
+                let next = self.endpoint(after);
+                let seg_first = self.endpoint(Some(start as isize));
+                let seg_last = self.endpoint(Some(end as isize));
+
+                // gain from closing the gap left behind by removing the segment
+                let removal_gain = self.endpoint_distance(prev, seg_first)
+                    + self.endpoint_distance(seg_last, next)
+                    - self.endpoint_distance(prev, next);
+
+                // try inserting the segment after every other position j (i.e.
+                // between stop j and stop j+1); j == -1 means "before index 0"
+                for j in -1isize..n as isize {
+                    if j >= start as isize - 1 && j <= end as isize {
+                        continue; // overlaps the segment or its immediate neighbors
+                    }
+                    let left = self.endpoint(if j < 0 { None } else { Some(j) });
+                    let right_idx = j + 1;
+                    let right = self.endpoint(if right_idx >= n as isize { None } else { Some(right_idx) });
+                    let removed_insert_edge = self.endpoint_distance(left, right);
+
+                    for &reversed in &[false, true] {
+                        let (first, last) = if reversed {
+                            (seg_last, seg_first)
+                        } else {
+                            (seg_first, seg_last)
+                        };
+                        let added_insert_edges =
+                            self.endpoint_distance(left, first) + self.endpoint_distance(last, right);
+                        let delta = added_insert_edges - removed_insert_edge - removal_gain;
+                        if delta < -1e-6 {
+                            self.apply_or_opt_move(start, seg_len, j, reversed);
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Removes the `seg_len` stops starting at `start` and reinserts them
+    /// (reversed if `reversed`) right after original index `j` (`j == -1`
+    /// means at the very front), accounting for the index shift the removal
+    /// causes.
+    fn apply_or_opt_move(&mut self, start: usize, seg_len: usize, j: isize, reversed: bool) {
+        let end = start + seg_len; // exclusive
+        let mut segment: Vec<Stop> = self.stops.drain(start..end).collect();
+        if reversed {
+            segment.reverse();
+        }
+
+        let insert_at = if j < 0 {
+            0
+        } else if (j as usize) < start {
+            (j as usize) + 1
+        } else {
+            (j as usize) - seg_len + 1
+        };
+
+        for (offset, s) in segment.into_iter().enumerate() {
+            self.stops.insert(insert_at + offset, s);
+        }
+    }
+
+    /// 3-opt: considers the seven non-trivial reconnection patterns that
+    /// result from removing three edges at cut points `i < j < k`. At the first
+    /// cut triple where any pattern reduces total distance, it applies the
+    /// shortest of those patterns, then searches again. This can escape
+    /// arrangements that neither 2-opt's reversals nor or-opt's relocations
+    /// alone can improve.
+    fn three_opt(&mut self) {
+        let mut improved = true;
+        while improved {
+            improved = false;
+            let n = self.stops.len();
+            if n < 5 {
+                return;
+            }
+            'search: for i in 0..n - 4 {
+                for j in i + 1..n - 2 {
+                    for k in j + 1..n - 1 {
+                        if let Some(reordered) = self.best_three_opt_reconnection(i, j, k) {
+                            self.stops = reordered;
+                            improved = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Cut points `i < j < k` split the route into four segments `A = [0..=i]`,
+    /// `B = (i..=j]`, `C = (j..=k]`, `D = (k..]`. Of the seven ways to reconnect
+    /// `B` and `C` (each kept or reversed, kept in order or swapped) between `A`
+    /// and `D`, returns the shortest one that is strictly shorter than the
+    /// current arrangement, or `None` if none improve on it.
+    fn best_three_opt_reconnection(&self, i: usize, j: usize, k: usize) -> Option<Vec<Stop>> {
+        let a = &self.stops[..=i];
+        let b = &self.stops[i + 1..=j];
+        let c = &self.stops[j + 1..=k];
+        let d = &self.stops[k + 1..];
+
+        let mut b_rev = b.to_vec();
+        b_rev.reverse();
+        let mut c_rev = c.to_vec();
+        c_rev.reverse();
+
+        let build = |mid_parts: &[&[Stop]]| -> Vec<Stop> {
+            let mut out = a.to_vec();
+            for part in mid_parts {
+                out.extend_from_slice(part);
+            }
+            out.extend_from_slice(d);
+            out
+        };
+
+        let candidates = [
+            build(&[&b_rev, c]),
+            build(&[b, &c_rev]),
+            build(&[&b_rev, &c_rev]),
+            build(&[c, b]),
+            build(&[&c_rev, b]),
+            build(&[c, &b_rev]),
+            build(&[&c_rev, &b_rev]),
+        ];
+
+        let current_dist = self.total_distance_meters();
+        candidates
+            .into_iter()
+            .map(|cand| {
+                let dist = self.path_distance(&cand);
+                (dist, cand)
+            })
+            .filter(|(dist, _)| *dist < current_dist - 1e-6)
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .map(|(_, stops)| stops)
+    }
+
+    /// Simulated annealing: escapes the local optima 2-opt alone gets stuck in.
+    /// Starts from the route's current order, repeatedly considers random segment
+    /// reversals, and accepts worsening moves with probability `exp(-delta / T)` so
+    /// it can climb out of a local minimum before `T` cools toward zero. The best
+    /// order seen across all iterations is restored at the end since the final
+    /// state can be worse than something visited along the way. `seed` makes runs
+    /// reproducible for testing.
+    fn optimize_annealing(&mut self, iterations: usize, start_temp: f64, cooling: f64, seed: u64) {
+        let n = self.stops.len();
+        if n < 3 {
+            return;
+        }
+        self.rebuild_distance_matrix();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut temp = start_temp;
+        let mut best_stops = self.stops.clone();
+        let mut best_dist = self.total_distance_meters();
+
+        for _ in 0..iterations {
+            let i = rng.gen_range(0..n - 1);
+            let k = rng.gen_range(i + 1..n);
+            if i == 0 && k == n - 1 && self.depot.is_none() {
+                temp *= cooling;
+                continue;
+            }
+
+            let delta = self.two_opt_swap_delta(i, k);
+            if delta < 0.0 || rng.gen_range(0.0..1.0) < (-delta / temp).exp() {
+                self.do_two_opt_swap(i, k);
+                let current_dist = self.total_distance_meters();
+                if current_dist < best_dist {
+                    best_dist = current_dist;
+                    best_stops = self.stops.clone();
+                }
+            }
+
+            temp *= cooling;
+        }
+
+        self.stops = best_stops;
+    }
+
+    /// Exact solver via Held-Karp dynamic programming over subsets. Only practical
+    /// for small routes, since both the state count and the per-state work grow
+    /// with `n`; routes larger than `EXACT_SOLVER_MAX_STOPS` fall back to the
+    /// heuristic `optimize` instead of risking the `2^n` blowup.
+    fn optimize_exact(&mut self) {
+        let n = self.stops.len();
+        if n < 2 {
+            return;
+        }
+        if n > Self::EXACT_SOLVER_MAX_STOPS {
+            self.optimize();
+            return;
+        }
+
+        // dist row/col 0 is the depot (or stops[0] if there is no depot); rows 1..=n
+        // line up with self.stops.
+        let depot = self.depot.clone().unwrap_or_else(|| self.stops[0].clone());
+        let mut points = Vec::with_capacity(n + 1);
+        points.push((depot.lat, depot.lon));
+        for s in &self.stops {
+            points.push((s.lat, s.lon));
+        }
+        let m = points.len();
+        let mut dist = vec![vec![0.0; m]; m];
+        for a in 0..m {
+            for b in 0..m {
+                dist[a][b] = haversine_meters(points[a].0, points[a].1, points[b].0, points[b].1);
+            }
+        }
+
+        // dp[mask][j]: min cost of a path from the depot that visits exactly the
+        // stops in `mask` (bit j is self.stops[j]) and ends at stop j (in mask).
+        let num_stops = n;
+        let full_mask = 1usize << num_stops;
+        let mut dp = vec![vec![f64::INFINITY; num_stops]; full_mask];
+        let mut parent = vec![vec![usize::MAX; num_stops]; full_mask];
+
+        for j in 0..num_stops {
+            let mask = 1usize << j;
+            dp[mask][j] = dist[0][j + 1];
+        }
+
+        for mask in 1..full_mask {
+            for j in 0..num_stops {
+                if mask & (1 << j) == 0 || dp[mask][j].is_infinite() {
+                    continue;
+                }
+                for next in 0..num_stops {
+                    if mask & (1 << next) != 0 {
+                        continue;
+                    }
+                    let next_mask = mask | (1 << next);
+                    let cost = dp[mask][j] + dist[j + 1][next + 1];
+                    if cost < dp[next_mask][next] {
+                        dp[next_mask][next] = cost;
+                        parent[next_mask][next] = j;
+                    }
+                }
+            }
+        }
+
+        let full = full_mask - 1;
+        let mut best_j = 0;
+        let mut best_cost = f64::INFINITY;
+        for j in 0..num_stops {
+            let cost = dp[full][j] + dist[j + 1][0];
+            if cost < best_cost {
+                best_cost = cost;
+                best_j = j;
+            }
+        }
+
+        // reconstruct the order by walking the parent chain back to the start
+        let mut order = Vec::with_capacity(num_stops);
+        let mut mask = full;
+        let mut j = best_j;
+        loop {
+            order.push(j);
+            let prev = parent[mask][j];
+            mask &= !(1 << j);
+            if prev == usize::MAX {
+                break;
+            }
+            j = prev;
+        }
+        order.reverse();
+
+        let original = self.stops.clone();
+        self.stops = order.into_iter().map(|idx| original[idx].clone()).collect();
+    }
+
+    /// Convenience: optimize by building NN, then alternating 2-opt, or-opt,
+    /// and 3-opt passes until none of them yields any further improvement.
     fn optimize(&mut self) {
         if self.stops.is_empty() {
             return;
@@ -206,8 +749,61 @@ impl Route {
         // copy current to temp and run NN on that set
         let all_stops = self.stops.clone();
         self.stops = all_stops;
-        self.build_nearest_neighbor();
-        self.two_opt();
+        self.rebuild_distance_matrix();
+        if self.coordinate_extent_degrees() <= Self::RTREE_MAX_EXTENT_DEGREES {
+            self.build_nearest_neighbor_rtree();
+        } else {
+            self.build_nearest_neighbor();
+        }
+
+        let mut last_dist = f64::INFINITY;
+        loop {
+            self.two_opt();
+            self.or_opt();
+            self.three_opt();
+            let dist = self.total_distance_meters();
+            if dist >= last_dist - 1e-6 {
+                break;
+            }
+            last_dist = dist;
+        }
+    }
+
+    /// Runs `restarts` independent NN + 2-opt/or-opt optimizations in parallel
+    /// (via rayon), each starting nearest-neighbor construction from a stop
+    /// chosen by an RNG seeded with the restart's index (so two restarts can
+    /// pick the same stop), and keeps whichever tour comes out shortest. A
+    /// single NN+2-opt pass is sensitive to its starting point; this trades CPU
+    /// for a noticeably better tour, mirroring the map-reduce style multi-start
+    /// approach used by dedicated TSP solvers.
+    ///
+    /// Note: each restart must build its own NN tour and run local search
+    /// directly (not call `optimize()`), since `optimize()` always re-anchors
+    /// NN construction at the depot and would collapse every restart to the
+    /// same tour regardless of the chosen starting stop.
+    fn optimize_multistart(&mut self, restarts: usize) {
+        if self.stops.len() < 2 || restarts == 0 {
+            self.optimize();
+            return;
+        }
+
+        let best = (0..restarts)
+            .into_par_iter()
+            .map(|i| {
+                let mut candidate = self.clone();
+                candidate.rebuild_distance_matrix();
+                let mut rng = StdRng::seed_from_u64(i as u64);
+                let start_idx = rng.gen_range(0..candidate.stops.len());
+                candidate.build_nearest_neighbor_from(start_idx);
+                candidate.two_opt();
+                candidate.or_opt();
+                (candidate.total_distance_meters(), candidate.stops)
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .expect("restarts > 0");
+
+        self.stops = best.1;
+        self.rebuild_distance_matrix();
     }
 
     fn print_summary(&self, avg_kmph: f64) {
@@ -223,6 +819,18 @@ impl Route {
     }
 }
 
+/// One row of `RouteManager::save_routes_csv`'s output: a single stop's
+/// position within its route, its leg distance, and the running time.
+#[derive(Serialize)]
+struct RouteCsvRow {
+    route_id: usize,
+    stop_order: usize,
+    stop_id: usize,
+    stop_name: String,
+    leg_distance_meters: f64,
+    cumulative_minutes: f64,
+}
+
 /// Manager for multiple routes (cleaners)
 struct RouteManager {
     routes: Vec<Route>,
@@ -241,6 +849,7 @@ impl RouteManager {
     fn add_stop_to_route(&mut self, route_id: usize, stop: Stop) {
         if let Some(r) = self.routes.iter_mut().find(|r| r.id == route_id) {
             r.stops.push(stop);
+            r.invalidate_distance_matrix();
         } else {
             eprintln!("Route {} not found", route_id);
         }
@@ -250,7 +859,9 @@ impl RouteManager {
     fn remove_stop_by_id(&mut self, stop_id: usize) -> Option<Stop> {
         for r in self.routes.iter_mut() {
             if let Some(pos) = r.stops.iter().position(|s| s.id == stop_id) {
-                return Some(r.stops.remove(pos));
+                let removed = r.stops.remove(pos);
+                r.invalidate_distance_matrix();
+                return Some(removed);
             }
         }
         None
@@ -273,12 +884,146 @@ impl RouteManager {
         }
     }
 
+    /// Optimize all routes in parallel, multi-starting each one. Parallelizes
+    /// across both the restarts within a route and the separate cleaner routes.
+    fn optimize_all_parallel(&mut self, restarts: usize) {
+        self.routes
+            .par_iter_mut()
+            .for_each(|r| r.optimize_multistart(restarts));
+    }
+
+    /// Clarke-Wright savings heuristic: start each stop on its own out-and-back
+    /// route from the depot, then repeatedly merge the pair of routes whose
+    /// endpoints have the largest savings `s(i,j) = d(depot,i) + d(depot,j) -
+    /// d(i,j)`, provided the merge keeps the combined route under
+    /// `max_minutes_per_cleaner` and neither `i` nor `j` is already an interior
+    /// stop of its route. Stops once `num_cleaners` routes remain, then
+    /// optimizes each one. This gives a geographically sensible, balanced split
+    /// instead of an arbitrary alternation across cleaners.
+    fn assign_savings(
+        stops: Vec<Stop>,
+        num_cleaners: usize,
+        depot: Stop,
+        avg_kmph: f64,
+        max_minutes_per_cleaner: f64,
+    ) -> RouteManager {
+        let n = stops.len();
+        let mut routes: Vec<Route> = stops
+            .iter()
+            .map(|s| {
+                let mut r = Route::new(s.id, Some(depot.clone()));
+                r.stops.push(s.clone());
+                r
+            })
+            .collect();
+        let mut location: std::collections::HashMap<usize, usize> =
+            stops.iter().enumerate().map(|(idx, s)| (s.id, idx)).collect();
+
+        let mut savings: Vec<(f64, usize, usize)> = Vec::with_capacity(n * n / 2);
+        for a in 0..n {
+            for b in (a + 1)..n {
+                let (si, sj) = (&stops[a], &stops[b]);
+                let s = haversine_meters(depot.lat, depot.lon, si.lat, si.lon)
+                    + haversine_meters(depot.lat, depot.lon, sj.lat, sj.lon)
+                    - haversine_meters(si.lat, si.lon, sj.lat, sj.lon);
+                savings.push((s, si.id, sj.id));
+            }
+        }
+        savings.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+        let is_endpoint = |r: &Route, id: usize| -> bool {
+            r.stops.first().map(|s| s.id) == Some(id) || r.stops.last().map(|s| s.id) == Some(id)
+        };
+
+        let mut active_count = n;
+        for (_, i_id, j_id) in savings {
+            if active_count <= num_cleaners {
+                break;
+            }
+            let (idx_a, idx_b) = match (location.get(&i_id), location.get(&j_id)) {
+                (Some(&a), Some(&b)) if a != b => (a, b),
+                _ => continue,
+            };
+            if !is_endpoint(&routes[idx_a], i_id) || !is_endpoint(&routes[idx_b], j_id) {
+                continue;
+            }
+
+            let mut merged = routes[idx_a].clone();
+            if merged.stops.first().map(|s| s.id) == Some(i_id) {
+                merged.stops.reverse();
+            }
+            let mut tail = routes[idx_b].stops.clone();
+            if tail.last().map(|s| s.id) == Some(j_id) {
+                tail.reverse();
+            }
+            merged.stops.extend(tail);
+
+            if merged.total_time_minutes(avg_kmph) > max_minutes_per_cleaner {
+                continue;
+            }
+
+            for s in &routes[idx_b].stops.clone() {
+                location.insert(s.id, idx_a);
+            }
+            routes[idx_a] = merged;
+            routes[idx_b].stops.clear();
+            active_count -= 1;
+        }
+
+        let mut manager = RouteManager::new();
+        for (next_id, r) in routes.into_iter().filter(|r| !r.stops.is_empty()).enumerate() {
+            let mut route = Route::new(next_id + 1, Some(depot.clone()));
+            route.stops = r.stops;
+            route.optimize();
+            manager.add_route(route);
+        }
+        manager
+    }
+
     fn print_all_summaries(&self, avg_kmph: f64) {
         for r in &self.routes {
             r.print_summary(avg_kmph);
             println!();
         }
     }
+
+    /// Load stops from a CSV file with header `id,name,lat,lon,service_minutes`.
+    fn load_stops_csv(path: &str) -> Result<Vec<Stop>, Box<dyn std::error::Error>> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let mut stops = Vec::new();
+        for record in reader.deserialize() {
+            stops.push(record?);
+        }
+        Ok(stops)
+    }
+
+    /// Write every route's optimized stop order to a CSV file, one row per stop
+    /// with its leg distance and cumulative time (travel + service) so far.
+    fn save_routes_csv(&self, path: &str, avg_kmph: f64) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = csv::Writer::from_path(path)?;
+        for r in &self.routes {
+            let mut prev = r.depot.as_ref();
+            let mut cumulative_minutes = 0.0;
+            for (order, s) in r.stops.iter().enumerate() {
+                let leg_meters = match prev {
+                    Some(p) => haversine_meters(p.lat, p.lon, s.lat, s.lon),
+                    None => 0.0,
+                };
+                cumulative_minutes += meters_to_minutes(leg_meters, avg_kmph) + s.service_minutes;
+                writer.serialize(RouteCsvRow {
+                    route_id: r.id,
+                    stop_order: order,
+                    stop_id: s.id,
+                    stop_name: s.name.clone(),
+                    leg_distance_meters: leg_meters,
+                    cumulative_minutes,
+                })?;
+                prev = Some(s);
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
 }
 
 /// Example usage: create some synthetic stops and demonstrate add/remove/reassign/optimize.
@@ -289,33 +1034,31 @@ fn main() {
     // create a (synthetic) depot (e.g., company yard)
     let depot = Stop::new(0, "Depot", 40.4406, -79.9959, 0.0); // Pittsburgh-ish coords
 
-    // sample stops (lat, lon roughly in Pittsburgh area). In real use, read from CSV or API.
-    let sample_stops = vec![
-        Stop::new(1, "Pool A", 40.4475, -79.9646, 10.0),
-        Stop::new(2, "Pool B", 40.4300, -80.0005, 12.0),
-        Stop::new(3, "Pool C", 40.4305, -79.9800, 8.0),
-        Stop::new(4, "Pool D", 40.4520, -79.9730, 15.0),
-        Stop::new(5, "Pool E", 40.4200, -79.9800, 10.0),
-        Stop::new(6, "Pool F", 40.4380, -80.0100, 10.0),
-        Stop::new(7, "Pool G", 40.4450, -80.0050, 10.0),
-    ];
+    // sample stops (lat, lon roughly in Pittsburgh area); falls back to a
+    // hard-coded list when there's no stops.csv to point the tool at.
+    let sample_stops = RouteManager::load_stops_csv("stops.csv").unwrap_or_else(|_| {
+        vec![
+            Stop::new(1, "Pool A", 40.4475, -79.9646, 10.0),
+            Stop::new(2, "Pool B", 40.4300, -80.0005, 12.0),
+            Stop::new(3, "Pool C", 40.4305, -79.9800, 8.0),
+            Stop::new(4, "Pool D", 40.4520, -79.9730, 15.0),
+            Stop::new(5, "Pool E", 40.4200, -79.9800, 10.0),
+            Stop::new(6, "Pool F", 40.4380, -80.0100, 10.0),
+            Stop::new(7, "Pool G", 40.4450, -80.0050, 10.0),
+        ]
+    });
 
-    // create two routes/cleaners
-    let mut manager = RouteManager::new();
-    let mut r1 = Route::new(1, Some(depot.clone()));
-    let mut r2 = Route::new(2, Some(depot.clone()));
-
-    // naive split: first N/2 go to route1, rest to route2
-    for (i, s) in sample_stops.into_iter().enumerate() {
-        if i % 2 == 0 {
-            r1.stops.push(s);
-        } else {
-            r2.stops.push(s);
-        }
-    }
-
-    manager.add_route(r1);
-    manager.add_route(r2);
+    // split stops across two cleaners with Clarke-Wright savings instead of an
+    // arbitrary i % 2 alternation, so each cleaner gets a geographically
+    // sensible subset that still fits under the per-cleaner time cap.
+    let max_minutes_per_cleaner = 180.0;
+    let mut manager = RouteManager::assign_savings(
+        sample_stops,
+        2,
+        depot.clone(),
+        avg_kmph,
+        max_minutes_per_cleaner,
+    );
 
     println!("Before optimization:");
     manager.print_all_summaries(avg_kmph);
@@ -347,8 +1090,117 @@ fn main() {
         println!("Reassigned stop 2 to Route 1");
     }
 
-    // Re-optimize
-    manager.optimize_all();
+    // Re-optimize, multi-starting each route in parallel for a better tour
+    // than a single NN + 2-opt/or-opt pass.
+    manager.optimize_all_parallel(8);
     println!("[Final optimized routes]");
     manager.print_all_summaries(avg_kmph);
+
+    // Demonstrate simulated annealing as an alternative to the default
+    // NN + 2-opt/or-opt/3-opt optimizer: same seed, same result, so this is
+    // a good fit when a route needs one more reproducible pass to escape a
+    // local optimum the default loop already converged on.
+    if let Some(route) = manager.routes.iter_mut().find(|r| r.id == 1) {
+        route.optimize_annealing(2000, 50.0, 0.995, 42);
+    }
+    println!("[After simulated annealing pass on Route 1]");
+    manager.print_all_summaries(avg_kmph);
+
+    // Demonstrate the exact Held-Karp solver on a small standalone route: at
+    // this size it finds the true optimum directly instead of the heuristic's
+    // NN + local-search approximation.
+    let mut small_route = Route::new(100, Some(depot.clone()));
+    small_route.stops = vec![
+        Stop::new(201, "Pool X", 40.4430, -79.9700, 10.0),
+        Stop::new(202, "Pool Y", 40.4470, -79.9900, 10.0),
+        Stop::new(203, "Pool Z", 40.4350, -79.9850, 10.0),
+        Stop::new(204, "Pool W", 40.4500, -79.9600, 10.0),
+    ];
+    small_route.optimize_exact();
+    println!("[Exact solver demo route]");
+    small_route.print_summary(avg_kmph);
+
+    if let Err(e) = manager.save_routes_csv("routes_output.csv", avg_kmph) {
+        eprintln!("Failed to save routes to CSV: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_route() -> Route {
+        let depot = Stop::new(0, "Depot", 40.4406, -79.9959, 0.0);
+        let mut route = Route::new(1, Some(depot));
+        route.stops = vec![
+            Stop::new(1, "A", 40.4475, -79.9646, 10.0),
+            Stop::new(2, "B", 40.4300, -80.0005, 12.0),
+            Stop::new(3, "C", 40.4305, -79.9800, 8.0),
+            Stop::new(4, "D", 40.4520, -79.9730, 15.0),
+            Stop::new(5, "E", 40.4200, -79.9800, 10.0),
+            Stop::new(6, "F", 40.4380, -80.0100, 10.0),
+        ];
+        route.rebuild_distance_matrix();
+        route
+    }
+
+    #[test]
+    fn optimize_annealing_is_reproducible_for_a_given_seed() {
+        let mut route_a = sample_route();
+        let mut route_b = sample_route();
+
+        route_a.optimize_annealing(500, 50.0, 0.99, 7);
+        route_b.optimize_annealing(500, 50.0, 0.99, 7);
+
+        let ids_a: Vec<usize> = route_a.stops.iter().map(|s| s.id).collect();
+        let ids_b: Vec<usize> = route_b.stops.iter().map(|s| s.id).collect();
+        assert_eq!(ids_a, ids_b);
+        assert_eq!(route_a.total_distance_meters(), route_b.total_distance_meters());
+    }
+
+    #[test]
+    fn optimize_exact_is_never_worse_than_the_heuristic() {
+        let mut exact_route = sample_route();
+        let mut heuristic_route = sample_route();
+
+        exact_route.optimize_exact();
+        heuristic_route.optimize();
+
+        let exact_dist = exact_route.total_distance_meters();
+        let heuristic_dist = heuristic_route.total_distance_meters();
+        assert!(
+            exact_dist <= heuristic_dist + 1e-6,
+            "exact solver ({exact_dist}) should find a tour at least as short as the heuristic's ({heuristic_dist})"
+        );
+    }
+
+    #[test]
+    fn optimize_multistart_is_never_worse_than_a_single_pass() {
+        let mut multistart_route = sample_route();
+        let mut single_pass_route = sample_route();
+
+        multistart_route.optimize_multistart(8);
+        single_pass_route.optimize();
+
+        let multistart_dist = multistart_route.total_distance_meters();
+        let single_pass_dist = single_pass_route.total_distance_meters();
+        assert!(
+            multistart_dist <= single_pass_dist + 1e-6,
+            "multistart ({multistart_dist}) should be at least as good as a single pass ({single_pass_dist})"
+        );
+    }
+
+    #[test]
+    fn multistart_restarts_use_distinct_starting_stops() {
+        let route = sample_route();
+        let mut seen_starts = std::collections::HashSet::new();
+        for i in 0..route.stops.len() as u64 {
+            let mut rng = StdRng::seed_from_u64(i);
+            seen_starts.insert(rng.gen_range(0..route.stops.len()));
+        }
+        assert!(
+            seen_starts.len() > 1,
+            "seeded restarts should pick more than one distinct starting stop"
+        );
+    }
 }